@@ -1,9 +1,14 @@
 use ecrs::ga::{individual::IndividualTrait, Probe};
 use itertools::Itertools;
 use log::info;
+use rand::Rng;
 
 use super::individual::JsspIndividual;
 
+/// Pairs sampled per call to [`JsspProbe::estimate_genotype_diversity`], to
+/// keep the estimate O(k) rather than O(n^2) on large populations.
+const GENOTYPE_DIVERSITY_SAMPLE_PAIRS: usize = 2_000;
+
 pub(crate) struct JsspProbe {}
 
 impl JsspProbe {
@@ -11,12 +16,73 @@ impl JsspProbe {
         Self {}
     }
 
-    // TODO: This has either been not working as expected or the solver runs so bad.
-    // TODO: Verify whether the diversity is better on other problems
-    fn estimate_pop_diversity(population: &[JsspIndividual]) -> f64 {
+    /// Mean pairwise Euclidean distance between chromosomes (the JSSP
+    /// chromosome is real-encoded, so Euclidean distance is the natural
+    /// genotype metric here; a bitstring encoding would instead want mean
+    /// Hamming distance). Pairs are sampled rather than exhaustively
+    /// enumerated once the population is large, to stay O(k) instead of
+    /// O(n^2).
+    ///
+    /// This replaces the previous `estimate_pop_diversity`, which hashed a
+    /// chromosome down to `(product of its genes * 1e5) as usize` — lossy
+    /// and collision-prone, since very different chromosomes can have the
+    /// same product and floating point error compounds over long products.
+    ///
+    /// Generic over [`IndividualTrait`] rather than [`JsspIndividual`]
+    /// specifically: it only calls `chromosome()`, so any other problem's
+    /// probe can reuse it as-is.
+    fn estimate_genotype_diversity<T: IndividualTrait<ChromosomeT = Vec<f64>>>(population: &[T]) -> f64 {
+        let n = population.len();
+        if n < 2 {
+            return 0.0;
+        }
+
+        let distance = |i: usize, j: usize| -> f64 {
+            population[i]
+                .chromosome()
+                .iter()
+                .zip(population[j].chromosome().iter())
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f64>()
+                .sqrt()
+        };
+
+        let all_pairs = n * (n - 1) / 2;
+        if all_pairs <= GENOTYPE_DIVERSITY_SAMPLE_PAIRS {
+            let total_distance: f64 = (0..n)
+                .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+                .map(|(i, j)| distance(i, j))
+                .sum();
+            return total_distance / all_pairs as f64;
+        }
+
+        let mut rng = rand::thread_rng();
+        let total_distance: f64 = (0..GENOTYPE_DIVERSITY_SAMPLE_PAIRS)
+            .map(|_| {
+                let i = rng.gen_range(0..n);
+                let j = loop {
+                    let candidate = rng.gen_range(0..n);
+                    if candidate != i {
+                        break candidate;
+                    }
+                };
+
+                distance(i, j)
+            })
+            .sum();
+
+        total_distance / GENOTYPE_DIVERSITY_SAMPLE_PAIRS as f64
+    }
+
+    /// Phenotype diversity: the number of distinct fitness values in the
+    /// population, divided by the population size.
+    ///
+    /// Generic over [`IndividualTrait`], same rationale as
+    /// [`Self::estimate_genotype_diversity`]: it only calls `get_fitness()`.
+    fn estimate_phenotype_diversity<T: IndividualTrait>(population: &[T]) -> f64 {
         population
             .iter()
-            .map(|idv| (idv.chromosome().iter().product::<f64>() * 100_000f64) as usize)
+            .map(|idv| idv.get_fitness().to_bits())
             .unique()
             .count() as f64
             / population.len() as f64
@@ -25,7 +91,7 @@ impl JsspProbe {
 
 impl Probe<JsspIndividual> for JsspProbe {
     // CSV OUTLINE:
-    // diversity,<generation>,<total_duration>,<population_size>,<diversity>
+    // diversity,<generation>,<total_duration>,<population_size>,<genotype_diversity>,<phenotype_diversity>
     // newbest,<generation>,<total_duration>,<fitness>
     // bestingen,<generation>,<total_duration>,<fitness>
     // popgentime,<time>
@@ -34,7 +100,7 @@ impl Probe<JsspIndividual> for JsspProbe {
     #[inline]
     fn on_start(&mut self, _metadata: &ecrs::ga::GAMetadata) {
         // Writing csv header to each file
-        info!(target: "diversity", "event_name,generation,total_duration,population_size,diversity");
+        info!(target: "diversity", "event_name,generation,total_duration,population_size,genotype_diversity,phenotype_diversity");
         info!(target: "popgentime", "event_name,time");
         info!(target: "newbest", "event_name,generation,total_duration,fitness");
         info!(target: "bestingen", "event_name,generation,total_duration,fitness");
@@ -46,10 +112,13 @@ impl Probe<JsspIndividual> for JsspProbe {
         metadata: &ecrs::ga::GAMetadata,
         population: &[JsspIndividual],
     ) {
-        // TODO: As this metric is useless right now I'm disabling it temporarily
-        // let diversity = JsspProbe::estimate_pop_diversity(population);
-        let diversity = 0.0;
-        info!(target: "diversity", "diversity,0,0,{},{diversity}", population.len());
+        let genotype_diversity = JsspProbe::estimate_genotype_diversity(population);
+        let phenotype_diversity = JsspProbe::estimate_phenotype_diversity(population);
+        info!(
+            target: "diversity",
+            "diversity,0,0,{},{genotype_diversity},{phenotype_diversity}",
+            population.len()
+        );
         info!(target: "popgentime", "popgentime,{}", metadata.pop_gen_dur.unwrap().as_millis());
     }
 
@@ -64,12 +133,11 @@ impl Probe<JsspIndividual> for JsspProbe {
     }
 
     fn on_new_generation(&mut self, metadata: &ecrs::ga::GAMetadata, generation: &[JsspIndividual]) {
-        // TODO: As this metric is useless right now I'm disabling it temporarily
-        // let diversity = JsspProbe::estimate_pop_diversity(generation);
-        let diversity = 0.0;
+        let genotype_diversity = JsspProbe::estimate_genotype_diversity(generation);
+        let phenotype_diversity = JsspProbe::estimate_phenotype_diversity(generation);
         info!(
             target: "diversity",
-            "diversity,{},{},{},{diversity}",
+            "diversity,{},{},{},{genotype_diversity},{phenotype_diversity}",
             metadata.generation,
             metadata.total_dur.unwrap().as_millis(),
             generation.len()
@@ -112,3 +180,76 @@ impl Probe<JsspIndividual> for JsspProbe {
     ) { /* defaults to noop */
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct MockIndividual {
+        chromosome: Vec<f64>,
+        fitness: f64,
+    }
+
+    impl MockIndividual {
+        fn new(chromosome: Vec<f64>, fitness: f64) -> Self {
+            MockIndividual { chromosome, fitness }
+        }
+    }
+
+    impl IndividualTrait for MockIndividual {
+        type ChromosomeT = Vec<f64>;
+
+        fn chromosome(&self) -> &Self::ChromosomeT {
+            &self.chromosome
+        }
+
+        fn get_fitness(&self) -> f64 {
+            self.fitness
+        }
+    }
+
+    #[test]
+    fn estimate_phenotype_diversity_is_fraction_of_distinct_fitness_values() {
+        // Two individuals share a fitness value, one is distinct: 2 distinct
+        // values out of 3 individuals.
+        let population = vec![
+            MockIndividual::new(vec![0.0], 1.0),
+            MockIndividual::new(vec![0.0], 1.0),
+            MockIndividual::new(vec![0.0], 2.0),
+        ];
+
+        assert_eq!(JsspProbe::estimate_phenotype_diversity(&population), 2.0 / 3.0);
+    }
+
+    #[test]
+    fn estimate_phenotype_diversity_is_one_when_every_fitness_is_distinct() {
+        let population = vec![
+            MockIndividual::new(vec![0.0], 1.0),
+            MockIndividual::new(vec![0.0], 2.0),
+            MockIndividual::new(vec![0.0], 3.0),
+        ];
+
+        assert_eq!(JsspProbe::estimate_phenotype_diversity(&population), 1.0);
+    }
+
+    #[test]
+    fn estimate_genotype_diversity_of_two_individuals_is_their_exact_euclidean_distance() {
+        // With only two individuals there's exactly one possible pair, which
+        // a population this small enumerates exhaustively, so the result is
+        // exact.
+        let population = vec![
+            MockIndividual::new(vec![0.0, 0.0], 0.0),
+            MockIndividual::new(vec![3.0, 4.0], 0.0),
+        ];
+
+        assert_eq!(JsspProbe::estimate_genotype_diversity(&population), 5.0);
+    }
+
+    #[test]
+    fn estimate_genotype_diversity_of_fewer_than_two_individuals_is_zero() {
+        let population = vec![MockIndividual::new(vec![0.0, 0.0], 0.0)];
+
+        assert_eq!(JsspProbe::estimate_genotype_diversity(&population), 0.0);
+    }
+}
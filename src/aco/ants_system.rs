@@ -2,8 +2,10 @@ pub mod builder;
 pub mod probe;
 mod solution;
 
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 use std::iter::zip;
 
 pub use solution::Solution;
@@ -11,6 +13,7 @@ pub use solution::Solution;
 use crate::aco::pheromone::PheromoneUpdate;
 use crate::aco::AntSystemCfg;
 use crate::aco::FMatrix;
+use crate::stop_criteria::ProgressReport;
 
 /// Wrapper class for AntSystem algorithm.
 ///
@@ -22,19 +25,53 @@ pub struct AntSystem<P: PheromoneUpdate> {
 }
 
 impl<P: PheromoneUpdate> AntSystem<P> {
-  /// Executes the algorithm
+  /// Builds a new [`AntSystem`] over the provided initial pheromone matrix.
+  pub fn new(cfg: AntSystemCfg<P>, pheromone: FMatrix) -> Self {
+    let best_sol = Solution {
+      matrix: FMatrix::zeros(pheromone.nrows(), pheromone.ncols()),
+      cost: f64::INFINITY,
+    };
+
+    AntSystem {
+      cfg,
+      pheromone,
+      best_sol,
+    }
+  }
+
+  /// Executes the algorithm until `self.cfg.stop_criterion` fires.
+  ///
+  /// Replaces the previous fixed `0..self.cfg.iteration` loop: the stop
+  /// criterion is evaluated after every iteration against a
+  /// [`ProgressReport`], so composite/early-stopping policies (max
+  /// generations, target fitness, wall-clock budget, stagnation, ...) can be
+  /// swapped in without touching this loop.
   pub fn run(mut self) {
-    for i in 0..self.cfg.iteration {
-      self.cfg.probe.on_iteration_start(i);
-      self.iterate();
-      self.cfg.probe.on_iteration_end(i);
+    let start = std::time::Instant::now();
+    let mut generation = 0;
+
+    loop {
+      self.cfg.probe.on_iteration_start(generation);
+      self.iterate(generation);
+      self.cfg.probe.on_iteration_end(generation);
+
+      let report = ProgressReport {
+        generation,
+        best_fitness: self.best_sol.cost,
+        elapsed: start.elapsed(),
+      };
+      generation += 1;
+
+      if self.cfg.stop_criterion.should_stop(&report) {
+        break;
+      }
     }
 
     self.end()
   }
 
-  fn iterate(&mut self) {
-    let sols_m = self.run_ants();
+  fn iterate(&mut self, generation: usize) {
+    let sols_m = self.run_ants(generation);
     let sols = self.grade(sols_m);
 
     let best = self.find_best(&sols);
@@ -67,7 +104,8 @@ impl<P: PheromoneUpdate> AntSystem<P> {
   }
 
   fn grade(&self, sols_m: Vec<FMatrix>) -> Vec<Solution> {
-    let costs: Vec<f64> = Vec::from_iter(sols_m.iter().map(|s| self.grade_one(s)));
+    let costs: Vec<f64> = sols_m.iter().map(|s| self.grade_one(s)).collect();
+
     let mut sols: Vec<Solution> = Vec::new();
     for (m, c) in zip(sols_m, costs) {
       sols.push(Solution { matrix: m, cost: c })
@@ -80,7 +118,7 @@ impl<P: PheromoneUpdate> AntSystem<P> {
     s.component_mul(&self.cfg.weights).sum() / 2.0
   }
 
-  fn run_ants(&self) -> Vec<FMatrix> {
+  fn run_ants(&self, generation: usize) -> Vec<FMatrix> {
     let prob_iter = self
       .pheromone
       .iter()
@@ -89,9 +127,31 @@ impl<P: PheromoneUpdate> AntSystem<P> {
 
     let prob = FMatrix::from_iterator(self.pheromone.nrows(), self.pheromone.ncols(), prob_iter);
 
-    let sols: Vec<FMatrix> = Vec::from_iter((0..self.cfg.ants_num).map(|_| run_ant(&prob)));
+    // Each ant gets its own seeded generator (see `ant_rng`) rather than
+    // sharing one `rand::thread_rng()`, so when `cfg.seed` is set runs are
+    // reproducible ant-by-ant regardless of how this loop is later
+    // restructured.
+    Vec::from_iter((0..self.cfg.ants_num).map(|i| run_ant(&prob, self.ant_rng(generation, i))))
+  }
 
-    sols
+  /// Builds the RNG used to construct ant `ant_index`'s tour in `generation`.
+  ///
+  /// When `cfg.seed` is set, the generator is deterministically seeded from
+  /// a hash of `(seed, generation, ant_index)`, so a run is reproducible
+  /// while still drawing a fresh stream each generation — folding in only
+  /// `ant_index` would otherwise replay the exact same tour and random draws
+  /// for ant `i` every generation, collapsing its exploration across the
+  /// whole run. When no seed is configured, falls back to OS entropy,
+  /// matching the previous unseeded behaviour.
+  fn ant_rng(&self, generation: usize, ant_index: usize) -> StdRng {
+    match self.cfg.seed {
+      Some(seed) => {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        (seed, generation, ant_index).hash(&mut hasher);
+        StdRng::seed_from_u64(hasher.finish())
+      }
+      None => StdRng::from_entropy(),
+    }
   }
 
   fn calc_prob(&self, p: &f64, h: &f64) -> f64 {
@@ -103,13 +163,12 @@ impl<P: PheromoneUpdate> AntSystem<P> {
   }
 }
 
-fn run_ant(prob: &FMatrix) -> FMatrix {
+fn run_ant(prob: &FMatrix, mut rng: StdRng) -> FMatrix {
   let n = prob.nrows();
   let mut sol = FMatrix::zeros(n, n);
-  let mut random = rand::thread_rng();
   let mut unvisited: HashSet<usize> = HashSet::from_iter(0..n);
 
-  let first: usize = random.gen_range(0..n);
+  let first: usize = rng.gen_range(0..n);
   unvisited.remove(&first);
   let mut last: usize = first;
 
@@ -126,7 +185,7 @@ fn run_ant(prob: &FMatrix) -> FMatrix {
       return FMatrix::zeros(n, n);
     }
 
-    let mut r = random.gen_range(r_range);
+    let mut r = rng.gen_range(r_range);
     let mut next = last; // maybe 0
     for v in unvisited.iter() {
       r -= row[*v];
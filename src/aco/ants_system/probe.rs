@@ -0,0 +1,22 @@
+use crate::aco::FMatrix;
+
+use super::Solution;
+
+/// Hooks for observing an [`crate::aco::ants_system::AntSystem`] run.
+///
+/// Every method has a no-op default, so an implementor only needs to
+/// override the events it cares about — mirroring [`crate::pbil::probe::Probe`]
+/// and [`crate::ga::Probe`].
+pub trait Probe {
+  fn on_iteration_start(&mut self, _generation: usize) {}
+  fn on_iteration_end(&mut self, _generation: usize) {}
+  fn on_current_best(&mut self, _best: &Solution) {}
+  fn on_new_best(&mut self, _best: &Solution) {}
+  fn on_pheromone_update(&mut self, _old_pheromone: &FMatrix, _new_pheromone: &FMatrix) {}
+  fn on_end(&mut self) {}
+}
+
+/// A [`Probe`] that discards every event.
+pub struct NoopProbe;
+
+impl Probe for NoopProbe {}
@@ -0,0 +1,37 @@
+pub mod ants_system;
+pub mod pheromone;
+
+use ants_system::probe::Probe;
+use pheromone::PheromoneUpdate;
+
+use crate::stop_criteria::StopCriterion;
+
+/// Matrix type used throughout this module: pheromone levels, heuristic
+/// information and ant tours are all square matrices over `f64`.
+pub type FMatrix = nalgebra::DMatrix<f64>;
+
+/// Configuration for [`ants_system::AntSystem`].
+pub struct AntSystemCfg<P: PheromoneUpdate> {
+	pub ants_num: usize,
+	pub alpha: f64,
+	pub beta: f64,
+	pub evaporation_rate: f64,
+	pub weights: FMatrix,
+	pub heuristic: FMatrix,
+	pub pheromone_update: P,
+	pub probe: Box<dyn Probe>,
+
+	/// Condition under which [`ants_system::AntSystem::run`] stops its main
+	/// loop, evaluated once per iteration.
+	pub stop_criterion: Box<dyn StopCriterion>,
+
+	/// Seed for the per-ant RNGs used to construct tours.
+	///
+	/// When set, every ant gets a dedicated per-generation generator seeded
+	/// from a hash of `(seed, generation, ant_index)`, so a run is
+	/// reproducible run-to-run (ant `i` in generation `g` always draws from
+	/// the same stream of randomness), while each ant still draws a fresh
+	/// stream every generation instead of replaying the same one. When
+	/// `None`, each ant's generator is seeded from OS entropy as before.
+	pub seed: Option<u64>,
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::collections::hash_map::DefaultHasher;
+
+/// A drop-in, config-gated cache that memoizes fitness evaluations by a hash
+/// of the chromosome.
+///
+/// Populations with low diversity (e.g. after several generations with a
+/// weak mutation operator) tend to re-evaluate the same genotype many times
+/// over; wrapping the evaluation step with [`FitnessCache::get_or_eval`]
+/// turns those repeats into a hash map lookup instead of a full re-grade.
+/// Disabled caches (`enabled: false`) are a pure passthrough, so turning
+/// caching off costs only a branch.
+///
+/// This assumes `eval` is a pure function of the chromosome's genes: a
+/// stochastic evaluator (e.g. one that re-simulates with fresh randomness
+/// each call) must disable the cache, since an identical genotype would
+/// otherwise silently reuse a stale fitness from an earlier, different
+/// evaluation.
+///
+/// Chromosomes in this crate are sequences of `f64`, which has no native
+/// [`Hash`] impl, so the key is derived from each gene's bit pattern rather
+/// than requiring `T: Hash` on the chromosome type itself.
+///
+/// STATUS: blocked, not wired into anything. The request this module
+/// implements asked for the GA's population-grading step (where each
+/// individual's fitness/makespan actually gets computed, the cost
+/// `GAMetadata::pop_eval_dur` tracks) to wrap its evaluation call in
+/// [`FitnessCache::get_or_eval`]. That call site lives in the GA's core
+/// generation loop, which this tree doesn't contain — there is no
+/// `ga/mod.rs` here to wire it into. Two prior attempts at an integration
+/// point both had to be reverted rather than kept as a substitute: first
+/// wiring `get_or_eval` into `AntSystem::grade_one` plus a matching
+/// `Probe::on_fitness_cache_stats` hook, then realizing `AntSystem` is ACO,
+/// not the GA the request is about, and backing both out. This module is
+/// therefore a tested, reusable primitive with no caller anywhere in this
+/// tree, not a completed feature — do not treat it as closed until the
+/// real grading step exists here and calls it.
+pub struct FitnessCache {
+	enabled: bool,
+	cache: HashMap<u64, f64>,
+	hits: usize,
+	misses: usize,
+}
+
+impl FitnessCache {
+	pub fn new(enabled: bool) -> Self {
+		FitnessCache {
+			enabled,
+			cache: HashMap::new(),
+			hits: 0,
+			misses: 0,
+		}
+	}
+
+	/// Returns the cached fitness for the chromosome yielded by `genes` if
+	/// present, otherwise calls `eval` to compute it and stores the result
+	/// for next time.
+	///
+	/// When the cache is disabled, `eval` is always called and nothing is
+	/// stored, so behaviour matches having no cache at all.
+	pub fn get_or_eval(&mut self, genes: impl IntoIterator<Item = f64>, eval: impl FnOnce() -> f64) -> f64 {
+		if !self.enabled {
+			return eval();
+		}
+
+		let key = Self::hash_of(genes);
+
+		if let Some(&fitness) = self.cache.get(&key) {
+			self.hits += 1;
+			return fitness;
+		}
+
+		self.misses += 1;
+		let fitness = eval();
+		self.cache.insert(key, fitness);
+		fitness
+	}
+
+	fn hash_of(genes: impl IntoIterator<Item = f64>) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		for gene in genes {
+			gene.to_bits().hash(&mut hasher);
+		}
+		hasher.finish()
+	}
+
+	pub fn hits(&self) -> usize {
+		self.hits
+	}
+
+	pub fn misses(&self) -> usize {
+		self.misses
+	}
+
+	/// Fraction of lookups so far that were served from the cache, in `[0, 1]`.
+	/// Returns `0.0` before the first lookup.
+	pub fn hit_rate(&self) -> f64 {
+		let total = self.hits + self.misses;
+		if total == 0 {
+			0.0
+		} else {
+			self.hits as f64 / total as f64
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn disabled_cache_always_calls_eval_and_reports_no_hits() {
+		let mut cache = FitnessCache::new(false);
+
+		assert_eq!(cache.get_or_eval(vec![1.0, 2.0], || 42.0), 42.0);
+		assert_eq!(cache.get_or_eval(vec![1.0, 2.0], || 42.0), 42.0);
+
+		assert_eq!(cache.hits(), 0);
+		assert_eq!(cache.misses(), 0);
+		assert_eq!(cache.hit_rate(), 0.0);
+	}
+
+	#[test]
+	fn enabled_cache_counts_a_repeated_lookup_as_a_hit() {
+		let mut cache = FitnessCache::new(true);
+
+		assert_eq!(cache.get_or_eval(vec![1.0, 2.0], || 42.0), 42.0);
+		assert_eq!(cache.misses(), 1);
+		assert_eq!(cache.hits(), 0);
+
+		assert_eq!(cache.get_or_eval(vec![1.0, 2.0], || panic!("should not re-evaluate a cached key")), 42.0);
+		assert_eq!(cache.misses(), 1);
+		assert_eq!(cache.hits(), 1);
+
+		assert_eq!(cache.hit_rate(), 0.5);
+	}
+
+	#[test]
+	fn enabled_cache_distinguishes_different_gene_sequences() {
+		let mut cache = FitnessCache::new(true);
+
+		assert_eq!(cache.get_or_eval(vec![1.0, 2.0], || 1.0), 1.0);
+		assert_eq!(cache.get_or_eval(vec![2.0, 1.0], || 2.0), 2.0);
+
+		assert_eq!(cache.misses(), 2);
+		assert_eq!(cache.hits(), 0);
+	}
+
+	#[test]
+	fn hit_rate_before_any_lookup_is_zero() {
+		let cache = FitnessCache::new(true);
+		assert_eq!(cache.hit_rate(), 0.0);
+	}
+}
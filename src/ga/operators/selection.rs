@@ -1,8 +1,22 @@
+//! Selection operators for the GA.
+//!
+//! STATUS: `apply_parallel` on [`RouletteWheel`], [`StochasticUniversalSampling`]
+//! and [`Boltzmann`] only parallelizes the total-fitness / weight-vector
+//! reduction each already does over the population — a secondary win at
+//! best. The request this implements primarily asked for population
+//! *grading* (the per-individual fitness/makespan evaluation that dominates
+//! `iterinfo`'s `eval_time`) to run across a rayon thread pool. That
+//! evaluation happens in the GA's core generation loop, which isn't part of
+//! this tree (no `ga/mod.rs` here), so there's no call site to parallelize
+//! it against. Left undone rather than faked; don't treat this as having
+//! closed the request's main ask.
+
 use std::ops::Index;
 
 use rand::Rng;
+use rayon::prelude::*;
 
-use crate::ga::{individual::{ChromosomeWrapper, Chromosome}, GAMetadata};
+use crate::ga::{individual::{ChromosomeWrapper, Chromosome}, rate_schedule::AdaptiveRate, GAMetadata};
 
 pub trait SelectionOperator<T: Chromosome, S: ChromosomeWrapper<T>> {
 	fn apply<'a>(&mut self, metadata: &GAMetadata, population: &'a [S], count: usize) -> Vec<&'a S>;
@@ -14,14 +28,26 @@ impl RouletteWheel {
 	pub fn new() -> Self {
 		RouletteWheel { }
 	}
-}
 
-impl<T: Chromosome, S: ChromosomeWrapper<T>> SelectionOperator<T, S> for RouletteWheel {
-	fn apply<'a> (&mut self, _metadata: &GAMetadata, population: &'a [S], count: usize) -> Vec<&'a S> {
-		let total_fitness: f64 = population.iter()
-			.map(|indiv| indiv.get_fitness())
-			.sum();
+	/// Parallel counterpart of [`SelectionOperator::apply`]: computes
+	/// `total_fitness` with a rayon parallel reduction instead of a
+	/// sequential sum. Fitness values are only read here, not computed, so
+	/// this does not parallelize grading itself.
+	///
+	/// Deliberately not part of the `SelectionOperator` trait impl: that impl
+	/// carries no `Sync` bound on `S`, so callers who never need parallelism
+	/// aren't forced to make their chromosome wrapper type `Sync`. Calling
+	/// this method instead opts into that extra bound explicitly.
+	pub fn apply_parallel<'a, T: Chromosome, S: ChromosomeWrapper<T> + Sync>(
+		&mut self,
+		population: &'a [S],
+		count: usize,
+	) -> Vec<&'a S> {
+		let total_fitness: f64 = population.par_iter().map(|indiv| indiv.get_fitness()).sum();
+		Self::select(population, count, total_fitness)
+	}
 
+	fn select<'a, T: Chromosome, S: ChromosomeWrapper<T>>(population: &'a [S], count: usize, total_fitness: f64) -> Vec<&'a S> {
 		let mut selected: Vec<&S> = Vec::with_capacity(count);
 
 		for _ in 0..count {
@@ -41,6 +67,13 @@ impl<T: Chromosome, S: ChromosomeWrapper<T>> SelectionOperator<T, S> for Roulett
 	}
 }
 
+impl<T: Chromosome, S: ChromosomeWrapper<T>> SelectionOperator<T, S> for RouletteWheel {
+	fn apply<'a> (&mut self, _metadata: &GAMetadata, population: &'a [S], count: usize) -> Vec<&'a S> {
+		let total_fitness: f64 = population.iter().map(|indiv| indiv.get_fitness()).sum();
+		Self::select(population, count, total_fitness)
+	}
+}
+
 pub struct Random;
 
 impl Random {
@@ -95,25 +128,55 @@ impl<T: Chromosome, S: ChromosomeWrapper<T>> SelectionOperator<T, S> for Rank {
 }
 
 pub struct RankR {
-	r: f64,
+	rate: Box<dyn AdaptiveRate>,
+	/// Best (highest, matching [`Rank`]'s `>=` polarity) fitness observed
+	/// across every generation this operator has graded so far, not just the
+	/// current population's extremum.
+	best_fitness_so_far: f64,
 }
 
 impl RankR {
+	/// Uses a fixed `r`, identical to the previous behaviour.
 	pub fn new(r: f64) -> Self {
 		assert!((0.0..=1.0).contains(&r));
 		RankR {
-			r,
+			rate: Box::new(crate::ga::rate_schedule::ConstantRate { rate: r }),
+			best_fitness_so_far: f64::NEG_INFINITY,
+		}
+	}
+
+	/// Drives `r` from an [`AdaptiveRate`] (e.g. [`crate::ga::rate_schedule::SlopeAdaptiveRate`])
+	/// instead of a fixed value, so `r` can e.g. rise once fitness
+	/// improvement stagnates.
+	pub fn with_adaptive_rate(rate: Box<dyn AdaptiveRate>) -> Self {
+		RankR {
+			rate,
+			best_fitness_so_far: f64::NEG_INFINITY,
 		}
 	}
 }
 
 impl<T: Chromosome, S: ChromosomeWrapper<T>> SelectionOperator<T, S> for RankR {
-	fn apply<'a>(&mut self, _metadata: &GAMetadata, population: &'a [S], count: usize) -> Vec<&'a S> {
+	fn apply<'a>(&mut self, metadata: &GAMetadata, population: &'a [S], count: usize) -> Vec<&'a S> {
 		let mut selected: Vec<&S> = Vec::with_capacity(count);
 		let population_len = population.len();
 		let distribution_for_ind = rand::distributions::Uniform::from(0..population_len);
 		let distribution_for_rand = rand::distributions::Uniform::from(0.0..1.0);
 
+		// Higher `get_fitness()` is "better" throughout this file (see
+		// `Rank`'s `p1.get_fitness() >= p2.get_fitness()` above), and
+		// `AdaptiveRate::rate`'s contract wants the best fitness *seen so
+		// far*, not this generation's extremum in isolation — without
+		// elitism a population's max can dip below a prior generation's, and
+		// feeding that dip to e.g. `SlopeAdaptiveRate` would misread real
+		// regression as renewed improvement.
+		let population_best = population
+			.iter()
+			.map(|idv| idv.get_fitness())
+			.fold(f64::NEG_INFINITY, f64::max);
+		self.best_fitness_so_far = self.best_fitness_so_far.max(population_best);
+		let r = self.rate.rate(metadata, self.best_fitness_so_far);
+
 		for _ in 0..count {
 			// TODO: Consider creating two random index permutations and then iterating over them
 			// instead of N times using random.
@@ -121,7 +184,7 @@ impl<T: Chromosome, S: ChromosomeWrapper<T>> SelectionOperator<T, S> for RankR {
 			let p2 = &population[rand::thread_rng().sample(distribution_for_ind)];
 
 			selected.push(
-				if rand::thread_rng().sample(distribution_for_rand) < self.r {
+				if rand::thread_rng().sample(distribution_for_rand) < r {
 					p1
 				} else {
 					p2
@@ -132,28 +195,60 @@ impl<T: Chromosome, S: ChromosomeWrapper<T>> SelectionOperator<T, S> for RankR {
 	}
 }
 
-pub struct Tournament;
+pub struct Tournament {
+	tournament_size: usize,
+	with_replacement: bool,
+}
 
 impl Tournament {
-	pub fn new() -> Self {
-		Tournament { }
+	/// `size` is the number of individuals drawn into each tournament; the
+	/// winner (highest fitness) of each tournament is selected. Sampling is
+	/// without replacement by default; use [`Tournament::with_replacement`]
+	/// to opt into drawing the same individual more than once within a
+	/// single tournament.
+	pub fn new(size: usize) -> Self {
+		assert!(size > 0, "tournament size must be greater than zero");
+		Tournament {
+			tournament_size: size,
+			with_replacement: false,
+		}
+	}
+
+	/// When `with_replacement` is `true`, the same individual may be drawn
+	/// more than once within a single tournament; otherwise `size` must not
+	/// exceed the population size passed to `apply`.
+	pub fn with_replacement(mut self, with_replacement: bool) -> Self {
+		self.with_replacement = with_replacement;
+		self
 	}
 }
 
 impl<T: Chromosome, S: ChromosomeWrapper<T>> SelectionOperator<T, S> for Tournament {
 	fn apply<'a>(&mut self, _metadata: &GAMetadata, population: &'a [S], count: usize) -> Vec<&'a S> {
-		// TODO: This operator must be parametrized...
-		// For now I fix value of this parameter
-		let tournament_size = population.len() / 5;
-
-		assert!(tournament_size > 0);
+		assert!(
+			self.with_replacement || self.tournament_size <= population.len(),
+			"tournament_size must not exceed population size when sampling without replacement"
+		);
 
 		let mut selected: Vec<&S> = Vec::with_capacity(count);
 
 		for _ in 0..count {
-			let tournament_indices = rand::seq::index::sample(&mut rand::thread_rng(), population.len(), tournament_size);
-			// FIXME: Check wheter the tournament_indices is empty or handle option below.
-			let best_idv  = tournament_indices.into_iter().map(|i| &population[i]).max().unwrap();
+			// `tournament_size > 0` is an invariant enforced by `new`, so
+			// both branches below always draw at least one individual and
+			// `max().unwrap()` cannot panic on an empty iterator.
+			let best_idv = if self.with_replacement {
+				(0..self.tournament_size)
+					.map(|_| &population[rand::thread_rng().gen_range(0..population.len())])
+					.max()
+					.unwrap()
+			} else {
+				rand::seq::index::sample(&mut rand::thread_rng(), population.len(), self.tournament_size)
+					.into_iter()
+					.map(|i| &population[i])
+					.max()
+					.unwrap()
+			};
+
 			selected.push(best_idv);
 		}
 
@@ -167,14 +262,18 @@ impl StochasticUniversalSampling {
 	pub fn new() -> Self {
 		StochasticUniversalSampling { }
 	}
-}
 
-impl<T: Chromosome, S: ChromosomeWrapper<T>> SelectionOperator<T, S> for StochasticUniversalSampling {
-	fn apply<'a>(&mut self, _metadata: &GAMetadata, population: &'a [S], count: usize) -> Vec<&'a S> {
-		let total_fitness: f64 = population.iter()
-			.map(|indiv| indiv.get_fitness())
-			.sum();
+	/// See [`RouletteWheel::apply_parallel`].
+	pub fn apply_parallel<'a, T: Chromosome, S: ChromosomeWrapper<T> + Sync>(
+		&mut self,
+		population: &'a [S],
+		count: usize,
+	) -> Vec<&'a S> {
+		let total_fitness: f64 = population.par_iter().map(|indiv| indiv.get_fitness()).sum();
+		Self::select(population, count, total_fitness)
+	}
 
+	fn select<'a, T: Chromosome, S: ChromosomeWrapper<T>>(population: &'a [S], count: usize, total_fitness: f64) -> Vec<&'a S> {
 		let mut selected: Vec<&S> = Vec::with_capacity(count);
 
 		let distance_between_pointers = total_fitness / (count as f64);
@@ -199,6 +298,205 @@ impl<T: Chromosome, S: ChromosomeWrapper<T>> SelectionOperator<T, S> for Stochas
 	}
 }
 
+impl<T: Chromosome, S: ChromosomeWrapper<T>> SelectionOperator<T, S> for StochasticUniversalSampling {
+	fn apply<'a>(&mut self, _metadata: &GAMetadata, population: &'a [S], count: usize) -> Vec<&'a S> {
+		let total_fitness: f64 = population.iter().map(|indiv| indiv.get_fitness()).sum();
+		Self::select(population, count, total_fitness)
+	}
+}
+
+/// Exposes a vector of objective values for an individual, in addition to the
+/// scalar [`ChromosomeWrapper::get_fitness`] value.
+///
+/// Required by multi-objective operators such as [`NsgaII`], which select
+/// parents based on Pareto dominance rather than a single scalar fitness.
+pub trait MultiObjective {
+	fn objectives(&self) -> &[f64];
+}
+
+/// Selects individuals according to the NSGA-II algorithm (Deb et al., 2002).
+///
+/// Objectives returned by [`MultiObjective::objectives`] are all treated as
+/// minimization targets: individual `a` dominates `b` iff `a` is no worse
+/// than `b` in every objective and strictly better in at least one.
+///
+/// The population is first partitioned into fronts of mutually
+/// non-dominating individuals via fast non-dominated sorting, and a crowding
+/// distance is computed within each front. `count` individuals are then
+/// drawn via binary tournament using NSGA-II's crowded-comparison operator
+/// ([`crowded_compare`]): of two randomly drawn individuals, the one in the
+/// better (lower) front wins; ties within a front go to whichever is less
+/// crowded, to keep the selected set spread out along the Pareto front. As
+/// with [`Tournament`], individuals may be drawn into more than one
+/// tournament, so `apply` always returns exactly `count` individuals
+/// regardless of how `count` compares to the population size.
+pub struct NsgaII;
+
+impl NsgaII {
+	pub fn new() -> Self {
+		NsgaII { }
+	}
+}
+
+impl<T, S> SelectionOperator<T, S> for NsgaII
+where
+	T: Chromosome,
+	S: ChromosomeWrapper<T> + MultiObjective,
+{
+	fn apply<'a>(&mut self, _metadata: &GAMetadata, population: &'a [S], count: usize) -> Vec<&'a S> {
+		assert!(!population.is_empty(), "cannot select from an empty population");
+
+		let fronts = fast_non_dominated_sort(population);
+
+		let mut rank = vec![0usize; population.len()];
+		for (front_index, front) in fronts.iter().enumerate() {
+			for &i in front {
+				rank[i] = front_index;
+			}
+		}
+
+		let mut distance: std::collections::HashMap<usize, f64> = std::collections::HashMap::new();
+		for front in &fronts {
+			distance.extend(crowding_distance(population, front));
+		}
+
+		let mut rng = rand::thread_rng();
+		let mut selected: Vec<&S> = Vec::with_capacity(count);
+
+		for _ in 0..count {
+			let a = rng.gen_range(0..population.len());
+			let b = rng.gen_range(0..population.len());
+			let winner = if crowded_compare(a, b, &rank, &distance) { a } else { b };
+			selected.push(&population[winner]);
+		}
+
+		assert_eq!(selected.len(), count);
+
+		selected
+	}
+}
+
+/// NSGA-II's crowded-comparison operator (`<_n`): `a` wins over `b` if it is
+/// in a strictly better (lower) front, or the two are in the same front and
+/// `a` has the larger crowding distance. Used by [`NsgaII`]'s binary
+/// tournament to pick a winner between two candidate indices into the same
+/// population.
+fn crowded_compare(a: usize, b: usize, rank: &[usize], distance: &std::collections::HashMap<usize, f64>) -> bool {
+	match rank[a].cmp(&rank[b]) {
+		std::cmp::Ordering::Less => true,
+		std::cmp::Ordering::Greater => false,
+		std::cmp::Ordering::Equal => distance[&a] > distance[&b],
+	}
+}
+
+/// Returns `true` if objective vector `a` Pareto-dominates `b`, assuming all
+/// objectives are to be minimized.
+fn dominates(a: &[f64], b: &[f64]) -> bool {
+	let mut strictly_better = false;
+	for (av, bv) in a.iter().zip(b.iter()) {
+		if av > bv {
+			return false;
+		}
+		if av < bv {
+			strictly_better = true;
+		}
+	}
+	strictly_better
+}
+
+/// Partitions `population` into fronts of mutually non-dominating
+/// individuals (indices into `population`), ordered from best (front 0) to
+/// worst.
+fn fast_non_dominated_sort<T, S>(population: &[S]) -> Vec<Vec<usize>>
+where
+	T: Chromosome,
+	S: ChromosomeWrapper<T> + MultiObjective,
+{
+	let n = population.len();
+	let mut dominated_by: Vec<Vec<usize>> = vec![Vec::new(); n];
+	let mut domination_count: Vec<usize> = vec![0; n];
+	let mut fronts: Vec<Vec<usize>> = vec![Vec::new()];
+
+	for p in 0..n {
+		for q in 0..n {
+			if p == q {
+				continue;
+			}
+			if dominates(population[p].objectives(), population[q].objectives()) {
+				dominated_by[p].push(q);
+			} else if dominates(population[q].objectives(), population[p].objectives()) {
+				domination_count[p] += 1;
+			}
+		}
+
+		if domination_count[p] == 0 {
+			fronts[0].push(p);
+		}
+	}
+
+	let mut i = 0;
+	while !fronts[i].is_empty() {
+		let mut next_front = Vec::new();
+		for &p in &fronts[i] {
+			for &q in &dominated_by[p] {
+				domination_count[q] -= 1;
+				if domination_count[q] == 0 {
+					next_front.push(q);
+				}
+			}
+		}
+		i += 1;
+		fronts.push(next_front);
+	}
+	fronts.pop(); // last front is always empty (loop terminates on it)
+
+	fronts
+}
+
+/// Computes the crowding distance of every individual in `front`, keyed by
+/// its index into `population`. Individuals at the extremes of an objective
+/// are assigned an infinite distance so they are always preferred.
+fn crowding_distance<T, S>(population: &[S], front: &[usize]) -> std::collections::HashMap<usize, f64>
+where
+	T: Chromosome,
+	S: ChromosomeWrapper<T> + MultiObjective,
+{
+	let mut distance: std::collections::HashMap<usize, f64> =
+		front.iter().map(|&i| (i, 0.0)).collect();
+
+	if front.is_empty() {
+		return distance;
+	}
+
+	let objective_count = population[front[0]].objectives().len();
+
+	for m in 0..objective_count {
+		let mut sorted = front.to_vec();
+		sorted.sort_by(|&a, &b| {
+			population[a].objectives()[m].partial_cmp(&population[b].objectives()[m]).unwrap()
+		});
+
+		let min = population[sorted[0]].objectives()[m];
+		let max = population[*sorted.last().unwrap()].objectives()[m];
+		let span = max - min;
+
+		*distance.get_mut(&sorted[0]).unwrap() = f64::INFINITY;
+		*distance.get_mut(sorted.last().unwrap()).unwrap() = f64::INFINITY;
+
+		if span == 0.0 {
+			continue;
+		}
+
+		for window in sorted.windows(3) {
+			let (prev, curr, next) = (window[0], window[1], window[2]);
+			let contribution = (population[next].objectives()[m] - population[prev].objectives()[m]) / span;
+			*distance.get_mut(&curr).unwrap() += contribution;
+		}
+	}
+
+	distance
+}
+
 pub struct Boltzmann {
 	alpha: f64,
 	max_gen_count: usize, // FIXME: This should be removed after operators are passed whole algorithm state & config
@@ -215,9 +513,44 @@ impl Boltzmann {
 			alpha,
 			max_gen_count,
 			temp_0,
-			elitism
+			elitism,
 		}
 	}
+
+	/// See [`RouletteWheel::apply_parallel`]; here it governs computation of
+	/// the per-individual weight vector rather than a single scalar sum.
+	pub fn apply_parallel<'a, T, S>(&mut self, metadata: &GAMetadata, population: &'a [S], count: usize) -> Vec<&'a S>
+	where
+		T: Chromosome + Index<usize, Output = f64>,
+		S: ChromosomeWrapper<T> + Sync,
+	{
+		let temp = self.temp(metadata);
+		let weights: Vec<f64> = population.par_iter().map(|idv| (-idv.get_fitness() / temp).exp()).collect();
+		Self::select(population, count, weights)
+	}
+
+	fn temp(&self, metadata: &GAMetadata) -> f64 {
+		let k = 1.0 + 100.0 * (metadata.generation.unwrap() as f64) / (self.max_gen_count as f64);
+		self.temp_0 * (1.0 - self.alpha).powf(k)
+	}
+
+	fn select<'a, T, S>(population: &'a [S], count: usize, weights: Vec<f64>) -> Vec<&'a S>
+	where
+		T: Chromosome + Index<usize, Output = f64>,
+		S: ChromosomeWrapper<T>,
+	{
+		let mut selected: Vec<&S> = Vec::with_capacity(count);
+
+		let Ok(indices) = rand::seq::index::sample_weighted(&mut rand::thread_rng(), population.len(), |i| weights[i], count) else {
+			panic!("Some error occured while generating indices. This is most likely an library implementation error. Please file an issue: https://github.com/kkafar/evolutionary-algorithms");
+		};
+
+		for i in indices {
+			selected.push(&population[i]);
+		}
+
+		selected
+	}
 }
 
 impl<T, S> SelectionOperator<T, S> for Boltzmann
@@ -226,25 +559,436 @@ where
 	S: ChromosomeWrapper<T>,
 {
 	fn apply<'a>(&mut self, metadata: &GAMetadata, population: &'a [S], count: usize) -> Vec<&'a S> {
+		let temp = self.temp(metadata);
+		let weights: Vec<f64> = population.iter().map(|idv| (-idv.get_fitness() / temp).exp()).collect();
+		Self::select(population, count, weights)
+	}
+}
 
-		let mut selected: Vec<&S> = Vec::with_capacity(count);
-		let mut weights: Vec<f64> = Vec::with_capacity(count);
+/// Fitness-sharing niching (Goldberg & Richardson, 1987).
+///
+/// Before running the wrapped [`Tournament`], every individual's raw fitness
+/// is divided by its niche count `m_i = sum_j sh(d_ij)`, where `d_ij` is the
+/// Euclidean distance between the genotypes of `i` and `j`, and
+/// `sh(d) = 1 - (d / sigma_share)^alpha` for `d < sigma_share`, `0`
+/// otherwise. Individuals crowded together in genotype space are penalized
+/// relative to ones that are more isolated, which discourages the whole
+/// population from converging onto a single optimum when the search space
+/// has several comparably-good ones.
+///
+/// Only [`Tournament`] can be wrapped: the inner operator is a `Tournament`
+/// field, and no other [`SelectionOperator`] is accepted.
+pub struct FitnessSharing {
+	tournament: Tournament,
+	sigma_share: f64,
+	alpha: f64,
+}
 
-		let k = 1.0 + 100.0 * (metadata.generation.unwrap() as f64) / (self.max_gen_count as f64);
-		let temp = self.temp_0 * (1.0 - self.alpha).powf(k);
+impl FitnessSharing {
+	pub fn new(tournament: Tournament, sigma_share: f64, alpha: f64) -> Self {
+		assert!(sigma_share > 0.0, "sigma_share must be positive");
+		FitnessSharing {
+			tournament,
+			sigma_share,
+			alpha,
+		}
+	}
 
-		for idv in population {
-			weights.push((-idv.get_fitness() / temp).exp())
+	fn sharing(&self, distance: f64) -> f64 {
+		if distance < self.sigma_share {
+			1.0 - (distance / self.sigma_share).powf(self.alpha)
+		} else {
+			0.0
 		}
+	}
 
-		let Ok(indices) = rand::seq::index::sample_weighted(&mut rand::thread_rng(), population.len(), |i| weights[i], count) else {
-			panic!("Some error occured while generating indices. This is most likely an library implementation error. Please file an issue: https://github.com/kkafar/evolutionary-algorithms");
-		};
+	fn distance<T: Chromosome + Index<usize, Output = f64>>(a: &T, b: &T) -> f64 {
+		(0..a.len())
+			.map(|i| (a[i] - b[i]).powi(2))
+			.sum::<f64>()
+			.sqrt()
+	}
+}
 
-		for i in indices {
-			selected.push(&population[i]);
+impl<T, S> SelectionOperator<T, S> for FitnessSharing
+where
+	T: Chromosome + Index<usize, Output = f64>,
+	S: ChromosomeWrapper<T>,
+{
+	fn apply<'a>(&mut self, metadata: &GAMetadata, population: &'a [S], count: usize) -> Vec<&'a S> {
+		let niche_counts: Vec<f64> = (0..population.len())
+			.map(|i| {
+				(0..population.len())
+					.map(|j| {
+						let distance = Self::distance(population[i].chromosome(), population[j].chromosome());
+						self.sharing(distance)
+					})
+					.sum()
+			})
+			.collect();
+
+		let shared_population: Vec<SharedFitnessView<T, S>> = population
+			.iter()
+			.zip(niche_counts)
+			.map(|(individual, m_i)| SharedFitnessView {
+				individual,
+				shared_fitness: individual.get_fitness() / m_i,
+				_chromosome: std::marker::PhantomData,
+			})
+			.collect();
+
+		let selected = self.tournament.apply(metadata, &shared_population, count);
+
+		selected.into_iter().map(|shared| shared.individual).collect()
+	}
+}
+
+/// An individual decorated with its fitness-sharing-adjusted fitness, so
+/// that [`Tournament`] (which only ever reads [`ChromosomeWrapper::get_fitness`])
+/// can run its comparisons against the shared value without being aware of
+/// niching at all.
+struct SharedFitnessView<'a, T, S> {
+	individual: &'a S,
+	shared_fitness: f64,
+	_chromosome: std::marker::PhantomData<T>,
+}
+
+impl<T: Chromosome, S: ChromosomeWrapper<T>> ChromosomeWrapper<T> for SharedFitnessView<'_, T, S> {
+	fn get_fitness(&self) -> f64 {
+		self.shared_fitness
+	}
+
+	fn chromosome(&self) -> &T {
+		self.individual.chromosome()
+	}
+}
+
+// `ChromosomeWrapper` requires `Ord` (that's what lets `Tournament::apply`'s
+// `.max()` compare individuals without ever naming a concrete wrapper type),
+// so `SharedFitnessView` needs its own impl rather than inheriting one.
+// Comparison delegates entirely to `shared_fitness`, same as `get_fitness`
+// above.
+impl<T, S> PartialEq for SharedFitnessView<'_, T, S> {
+	fn eq(&self, other: &Self) -> bool {
+		self.shared_fitness == other.shared_fitness
+	}
+}
+
+impl<T, S> Eq for SharedFitnessView<'_, T, S> {}
+
+impl<T, S> PartialOrd for SharedFitnessView<'_, T, S> {
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<T, S> Ord for SharedFitnessView<'_, T, S> {
+	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+		self.shared_fitness.partial_cmp(&other.shared_fitness).unwrap()
+	}
+}
+
+#[cfg(test)]
+mod nsga_ii_tests {
+	use super::*;
+
+	#[derive(Clone)]
+	struct MockChromosome;
+
+	impl Chromosome for MockChromosome {}
+
+	struct MockIndividual {
+		chromosome: MockChromosome,
+		objectives: Vec<f64>,
+	}
+
+	impl MockIndividual {
+		fn new(objectives: Vec<f64>) -> Self {
+			MockIndividual { chromosome: MockChromosome, objectives }
 		}
+	}
 
-		selected
+	impl ChromosomeWrapper<MockChromosome> for MockIndividual {
+		fn get_fitness(&self) -> f64 {
+			self.objectives.iter().sum()
+		}
+
+		fn chromosome(&self) -> &MockChromosome {
+			&self.chromosome
+		}
+	}
+
+	impl MultiObjective for MockIndividual {
+		fn objectives(&self) -> &[f64] {
+			&self.objectives
+		}
+	}
+
+	#[test]
+	fn dominates_requires_no_worse_and_one_strictly_better() {
+		assert!(dominates(&[1.0, 1.0], &[1.0, 2.0]));
+		assert!(!dominates(&[1.0, 1.0], &[1.0, 1.0]), "equal vectors must not dominate each other");
+		assert!(!dominates(&[2.0, 1.0], &[1.0, 1.0]));
+	}
+
+	#[test]
+	fn fast_non_dominated_sort_separates_dominated_individuals_into_later_fronts() {
+		let population = vec![
+			MockIndividual::new(vec![0.0, 0.0]), // dominates everything else
+			MockIndividual::new(vec![1.0, 1.0]),
+			MockIndividual::new(vec![2.0, 2.0]), // dominated by both others
+		];
+
+		let fronts = fast_non_dominated_sort(&population);
+
+		assert_eq!(fronts[0], vec![0]);
+		assert_eq!(fronts[1], vec![1]);
+		assert_eq!(fronts[2], vec![2]);
+	}
+
+	#[test]
+	fn fast_non_dominated_sort_groups_mutually_non_dominating_individuals_in_one_front() {
+		// Neither trades off strictly better/worse than the other on both objectives.
+		let population = vec![
+			MockIndividual::new(vec![0.0, 1.0]),
+			MockIndividual::new(vec![1.0, 0.0]),
+		];
+
+		let fronts = fast_non_dominated_sort(&population);
+
+		assert_eq!(fronts.len(), 1);
+		assert_eq!(fronts[0].len(), 2);
+	}
+
+	#[test]
+	fn crowding_distance_assigns_infinity_to_front_boundary_individuals() {
+		let population = vec![
+			MockIndividual::new(vec![0.0, 1.0]),
+			MockIndividual::new(vec![0.5, 0.5]),
+			MockIndividual::new(vec![1.0, 0.0]),
+		];
+		let front = vec![0, 1, 2];
+
+		let distance = crowding_distance(&population, &front);
+
+		assert_eq!(distance[&0], f64::INFINITY);
+		assert_eq!(distance[&2], f64::INFINITY);
+		assert!(distance[&1].is_finite());
+	}
+
+	#[test]
+	fn crowding_distance_on_single_element_front_does_not_panic() {
+		let population = vec![MockIndividual::new(vec![0.0, 0.0])];
+		let front = vec![0];
+
+		let distance = crowding_distance(&population, &front);
+
+		assert_eq!(distance[&0], f64::INFINITY);
+	}
+
+	#[test]
+	fn crowded_compare_prefers_lower_rank_then_higher_crowding_distance() {
+		let rank = vec![0, 1];
+		let mut distance = std::collections::HashMap::new();
+		distance.insert(0, 1.0);
+		distance.insert(1, 100.0);
+
+		// Lower rank always wins, even against a much less crowded individual.
+		assert!(crowded_compare(0, 1, &rank, &distance));
+		assert!(!crowded_compare(1, 0, &rank, &distance));
+
+		let same_rank = vec![0, 0];
+		assert!(crowded_compare(1, 0, &same_rank, &distance), "within a front, higher crowding distance wins");
+	}
+
+	#[test]
+	fn apply_always_returns_exactly_count_individuals() {
+		let population = vec![
+			MockIndividual::new(vec![0.0, 1.0]),
+			MockIndividual::new(vec![1.0, 0.0]),
+		];
+
+		let metadata = GAMetadata::default();
+		let mut op = NsgaII::new();
+
+		// `count` exceeding the population size must still be satisfied in full,
+		// by drawing some individuals into more than one tournament.
+		let selected = op.apply(&metadata, &population, 5);
+		assert_eq!(selected.len(), 5);
+	}
+}
+
+#[cfg(test)]
+mod tournament_tests {
+	use super::*;
+
+	#[derive(Clone)]
+	struct MockChromosome;
+
+	impl Chromosome for MockChromosome {}
+
+	struct MockIndividual {
+		chromosome: MockChromosome,
+		fitness: f64,
+	}
+
+	impl MockIndividual {
+		fn new(fitness: f64) -> Self {
+			MockIndividual { chromosome: MockChromosome, fitness }
+		}
+	}
+
+	impl ChromosomeWrapper<MockChromosome> for MockIndividual {
+		fn get_fitness(&self) -> f64 {
+			self.fitness
+		}
+
+		fn chromosome(&self) -> &MockChromosome {
+			&self.chromosome
+		}
+	}
+
+	impl PartialEq for MockIndividual {
+		fn eq(&self, other: &Self) -> bool {
+			self.fitness == other.fitness
+		}
+	}
+
+	impl Eq for MockIndividual {}
+
+	impl PartialOrd for MockIndividual {
+		fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+			Some(self.cmp(other))
+		}
+	}
+
+	impl Ord for MockIndividual {
+		fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+			self.fitness.partial_cmp(&other.fitness).unwrap()
+		}
+	}
+
+	#[test]
+	fn with_replacement_allows_tournament_size_to_exceed_population_size() {
+		// A single individual with a tournament size of 5 can only be
+		// satisfied by drawing the same index more than once; without
+		// replacement this combination would panic (`tournament_size` > population).
+		let population = vec![MockIndividual::new(1.0)];
+		let metadata = GAMetadata::default();
+		let mut op = Tournament::new(5).with_replacement(true);
+
+		let selected = op.apply(&metadata, &population, 3);
+		assert_eq!(selected.len(), 3);
+	}
+
+	#[test]
+	#[should_panic]
+	fn without_replacement_panics_when_tournament_size_exceeds_population_size() {
+		let population = vec![MockIndividual::new(1.0)];
+		let metadata = GAMetadata::default();
+		let mut op = Tournament::new(5);
+
+		op.apply(&metadata, &population, 3);
+	}
+}
+
+#[cfg(test)]
+mod rank_r_tests {
+	use super::*;
+	use std::cell::RefCell;
+	use std::rc::Rc;
+
+	#[derive(Clone)]
+	struct MockChromosome;
+
+	impl Chromosome for MockChromosome {}
+
+	struct MockIndividual {
+		chromosome: MockChromosome,
+		fitness: f64,
+	}
+
+	impl MockIndividual {
+		fn new(fitness: f64) -> Self {
+			MockIndividual { chromosome: MockChromosome, fitness }
+		}
+	}
+
+	impl ChromosomeWrapper<MockChromosome> for MockIndividual {
+		fn get_fitness(&self) -> f64 {
+			self.fitness
+		}
+
+		fn chromosome(&self) -> &MockChromosome {
+			&self.chromosome
+		}
+	}
+
+	/// Records every `best_fitness` value it's called with, so tests can
+	/// inspect what `RankR::apply` actually fed `AdaptiveRate::rate` across
+	/// several generations.
+	struct RecordingRate {
+		seen: Rc<RefCell<Vec<f64>>>,
+	}
+
+	impl AdaptiveRate for RecordingRate {
+		fn rate(&mut self, _metadata: &GAMetadata, best_fitness: f64) -> f64 {
+			self.seen.borrow_mut().push(best_fitness);
+			0.0
+		}
+	}
+
+	#[test]
+	fn apply_tracks_running_best_fitness_across_generations() {
+		let seen = Rc::new(RefCell::new(Vec::new()));
+		let mut op = RankR::with_adaptive_rate(Box::new(RecordingRate { seen: seen.clone() }));
+		let metadata = GAMetadata::default();
+
+		// Gen 1's max (5.0) is higher than Gen 2's max (3.0); without
+		// elitism the population can regress, but the running best must not.
+		let gen1 = vec![MockIndividual::new(1.0), MockIndividual::new(5.0)];
+		let gen2 = vec![MockIndividual::new(2.0), MockIndividual::new(3.0)];
+
+		op.apply(&metadata, &gen1, 1);
+		op.apply(&metadata, &gen2, 1);
+
+		assert_eq!(*seen.borrow(), vec![5.0, 5.0], "best-fitness-so-far must carry over, not reset to the current population's max");
+	}
+
+	#[test]
+	fn apply_uses_max_not_min_matching_ranks_higher_is_better_polarity() {
+		let seen = Rc::new(RefCell::new(Vec::new()));
+		let mut op = RankR::with_adaptive_rate(Box::new(RecordingRate { seen: seen.clone() }));
+		let metadata = GAMetadata::default();
+
+		let population = vec![MockIndividual::new(1.0), MockIndividual::new(5.0), MockIndividual::new(3.0)];
+		op.apply(&metadata, &population, 1);
+
+		assert_eq!(*seen.borrow(), vec![5.0], "best fitness is the population's max, matching Rank's p1 >= p2 polarity");
+	}
+}
+
+#[cfg(test)]
+mod fitness_sharing_tests {
+	use super::*;
+
+	#[test]
+	fn sharing_halves_fitness_for_a_duplicate_and_leaves_an_isolated_individual_unshared() {
+		let op = FitnessSharing::new(Tournament::new(2).with_replacement(true), /* sigma_share */ 1.0, /* alpha */ 1.0);
+
+		// sh(0) == 1: an identical chromosome fully overlaps the niche.
+		// Beyond sigma_share, sh(d) == 0: the chromosome falls outside it.
+		let within_niche = 0.0;
+		let outside_niche = 5.0;
+
+		// Duplicated once: shares its niche with exactly one other individual,
+		// on top of itself.
+		let niche_count_duplicate = op.sharing(within_niche) + op.sharing(within_niche);
+		// No one else inside its niche: only itself.
+		let niche_count_isolated = op.sharing(within_niche) + op.sharing(outside_niche);
+
+		let raw_fitness = 10.0;
+		assert_eq!(raw_fitness / niche_count_duplicate, raw_fitness / 2.0, "a duplicate's fitness should be halved");
+		assert_eq!(raw_fitness / niche_count_isolated, raw_fitness, "an isolated individual's fitness is unshared");
 	}
 }
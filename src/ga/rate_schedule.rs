@@ -0,0 +1,143 @@
+use std::collections::VecDeque;
+
+use crate::ga::GAMetadata;
+
+/// Drives a mutation or selection rate from generation progress, in place of
+/// a single fixed value.
+///
+/// Implementations read whatever they need from [`GAMetadata`] (generation
+/// index, elapsed time, ...) plus the best fitness seen so far, and return
+/// the rate to use for that iteration.
+///
+/// Only [`crate::ga::operators::selection::RankR::with_adaptive_rate`]
+/// consumes this so far. Nothing in this tree applies it to a mutation
+/// rate: there's no mutation operator module here for it to be wired into.
+/// The trait itself is generic enough to drive one once that operator
+/// exists — it would just read `rate()` the same way `RankR` does.
+pub trait AdaptiveRate {
+	fn rate(&mut self, metadata: &GAMetadata, best_fitness: f64) -> f64;
+}
+
+/// A rate that never changes; the default when no adaptation is desired.
+pub struct ConstantRate {
+	pub rate: f64,
+}
+
+impl AdaptiveRate for ConstantRate {
+	fn rate(&mut self, _metadata: &GAMetadata, _best_fitness: f64) -> f64 {
+		self.rate
+	}
+}
+
+/// Linearly interpolates from `start` to `end` over `generation_limit`
+/// generations, then holds at `end`.
+pub struct LinearSchedule {
+	pub start: f64,
+	pub end: f64,
+	pub generation_limit: usize,
+}
+
+impl AdaptiveRate for LinearSchedule {
+	fn rate(&mut self, metadata: &GAMetadata, _best_fitness: f64) -> f64 {
+		let generation = metadata.generation.unwrap_or(0) as f64;
+		let progress = (generation / self.generation_limit as f64).min(1.0);
+		self.start + (self.end - self.start) * progress
+	}
+}
+
+/// Exponentially decays from `start` towards zero as `start * decay^generation`.
+pub struct ExponentialDecay {
+	pub start: f64,
+	pub decay: f64,
+}
+
+impl AdaptiveRate for ExponentialDecay {
+	fn rate(&mut self, metadata: &GAMetadata, _best_fitness: f64) -> f64 {
+		let generation = metadata.generation.unwrap_or(0) as i32;
+		self.start * self.decay.powi(generation)
+	}
+}
+
+/// Switches the rate to `max` once improvement of the best fitness flattens
+/// out, and back to `min` once progress resumes.
+///
+/// Stagnation is judged over a sliding `window` of the most recent
+/// best-fitness values: if the relative improvement from the oldest to the
+/// newest value in the window is below `flatten_threshold`, the search is
+/// considered stagnant for this generation.
+pub struct SlopeAdaptiveRate {
+	pub min: f64,
+	pub max: f64,
+	pub window: usize,
+	pub flatten_threshold: f64,
+	history: VecDeque<f64>,
+}
+
+impl SlopeAdaptiveRate {
+	pub fn new(min: f64, max: f64, window: usize, flatten_threshold: f64) -> Self {
+		assert!(window > 0, "window must be greater than zero");
+		SlopeAdaptiveRate {
+			min,
+			max,
+			window,
+			flatten_threshold,
+			history: VecDeque::with_capacity(window),
+		}
+	}
+
+	fn is_stagnant(&self) -> bool {
+		let (Some(&oldest), Some(&newest)) = (self.history.front(), self.history.back()) else {
+			return false;
+		};
+
+		if self.history.len() < self.window {
+			return false;
+		}
+
+		let improvement = (oldest - newest).abs();
+		let scale = oldest.abs().max(1e-12);
+		improvement / scale < self.flatten_threshold
+	}
+}
+
+impl AdaptiveRate for SlopeAdaptiveRate {
+	fn rate(&mut self, _metadata: &GAMetadata, best_fitness: f64) -> f64 {
+		self.history.push_back(best_fitness);
+		if self.history.len() > self.window {
+			self.history.pop_front();
+		}
+
+		if self.is_stagnant() {
+			self.max
+		} else {
+			self.min
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn slope_adaptive_rate_raises_to_max_once_window_is_flat() {
+		let mut rate = SlopeAdaptiveRate::new(0.1, 0.9, 3, 0.01);
+		let metadata = GAMetadata::default();
+
+		assert_eq!(rate.rate(&metadata, 10.0), 0.1, "window not yet full, so not stagnant yet");
+		assert_eq!(rate.rate(&metadata, 10.0), 0.1, "window not yet full, so not stagnant yet");
+		assert_eq!(rate.rate(&metadata, 10.0), 0.9, "a full window of identical values is stagnant");
+	}
+
+	#[test]
+	fn slope_adaptive_rate_drops_back_to_min_once_progress_resumes() {
+		let mut rate = SlopeAdaptiveRate::new(0.1, 0.9, 3, 0.01);
+		let metadata = GAMetadata::default();
+
+		rate.rate(&metadata, 10.0);
+		rate.rate(&metadata, 10.0);
+		assert_eq!(rate.rate(&metadata, 10.0), 0.9, "stagnant after a flat window");
+
+		assert_eq!(rate.rate(&metadata, 1.0), 0.1, "a sharp improvement clears stagnation");
+	}
+}
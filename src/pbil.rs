@@ -0,0 +1,251 @@
+pub mod builder;
+pub mod probe;
+
+use std::time::Instant;
+
+use rand::Rng;
+
+use probe::{IterationTimings, Probe};
+
+/// Configuration for [`Pbil`].
+pub struct PbilCfg<Pr: Probe> {
+  /// Length of the binary-encoded solution vector.
+  pub solution_len: usize,
+  /// Number of bitstrings sampled from the probability vector each generation.
+  pub samples_per_gen: usize,
+  /// Rate at which the probability vector is moved towards the best sample
+  /// of a generation, in `[0, 1]`.
+  pub learning_rate: f64,
+  /// Rate at which the probability vector is nudged away from the worst
+  /// sample of a generation, in `[0, 1]`. Only entries where the worst
+  /// sample's bit disagrees with the best sample's bit are nudged — towards
+  /// the best sample's bit, same as [`Pbil::update_towards`] but at this
+  /// (typically smaller) rate — so a lone bad bit in an otherwise-strong
+  /// sample doesn't drag the whole vector. `None` disables this step
+  /// entirely, matching the original PBIL update.
+  pub negative_learning_rate: Option<f64>,
+  /// Probability that a given entry of the probability vector is perturbed
+  /// each generation, to counteract premature convergence.
+  pub mutation_prob: f64,
+  /// Magnitude of a single mutation perturbation.
+  pub mutation_shift: f64,
+  pub generation_limit: usize,
+  pub probe: Pr,
+}
+
+/// PBIL (Population-Based Incremental Learning).
+///
+/// Unlike the GA operators in [`crate::ga`], PBIL keeps no explicit
+/// population. Instead it maintains a real-valued probability vector `p` of
+/// length [`PbilCfg::solution_len`] (initialized to `0.5`), samples
+/// bitstrings from it each generation, and nudges `p` towards the best
+/// sampled bitstring. `eval` scores a candidate solution; lower is better,
+/// matching the convention used by [`crate::aco::ants_system::Solution`].
+pub struct Pbil<F: Fn(&[bool]) -> f64, Pr: Probe> {
+  cfg: PbilCfg<Pr>,
+  eval: F,
+  probabilities: Vec<f64>,
+  best: Option<(Vec<bool>, f64)>,
+}
+
+impl<F, Pr> Pbil<F, Pr>
+where
+  F: Fn(&[bool]) -> f64,
+  Pr: Probe,
+{
+  pub fn new(cfg: PbilCfg<Pr>, eval: F) -> Self {
+    assert!(cfg.samples_per_gen > 0, "samples_per_gen must be greater than zero");
+    assert!(cfg.generation_limit > 0, "generation_limit must be greater than zero");
+    assert!((0.0..=1.0).contains(&cfg.learning_rate), "learning_rate must be in [0, 1]");
+    if let Some(neg_lr) = cfg.negative_learning_rate {
+      assert!((0.0..=1.0).contains(&neg_lr), "negative_learning_rate must be in [0, 1]");
+    }
+    assert!((0.0..=1.0).contains(&cfg.mutation_prob), "mutation_prob must be in [0, 1]");
+    assert!((0.0..=1.0).contains(&cfg.mutation_shift), "mutation_shift must be in [0, 1]");
+
+    let probabilities = vec![0.5; cfg.solution_len];
+    Pbil {
+      cfg,
+      eval,
+      probabilities,
+      best: None,
+    }
+  }
+
+  /// Runs the algorithm to completion and returns the best solution found.
+  pub fn run(mut self) -> (Vec<bool>, f64) {
+    self.cfg.probe.on_start();
+
+    for generation in 0..self.cfg.generation_limit {
+      self.cfg.probe.on_iteration_start(generation);
+      self.iterate(generation);
+      self.cfg.probe.on_iteration_end(generation);
+    }
+
+    self.cfg.probe.on_end();
+
+    self.best.expect("run() always samples at least one generation")
+  }
+
+  fn iterate(&mut self, generation: usize) {
+    let sample_start = Instant::now();
+    let samples = self.sample();
+    let sample_dur = sample_start.elapsed();
+
+    let eval_start = Instant::now();
+    let graded: Vec<(Vec<bool>, f64)> = samples
+      .into_iter()
+      .map(|sample| {
+        let fitness = (self.eval)(&sample);
+        (sample, fitness)
+      })
+      .collect();
+    let eval_dur = eval_start.elapsed();
+
+    let best_in_gen = graded
+      .iter()
+      .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+      .expect("samples_per_gen must be greater than zero");
+    self.cfg.probe.on_best_in_generation(generation, best_in_gen);
+
+    let update_start = Instant::now();
+    self.update_towards(&best_in_gen.0);
+    if let Some(neg_lr) = self.cfg.negative_learning_rate {
+      let worst_in_gen = graded
+        .iter()
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .expect("samples_per_gen must be greater than zero");
+      self.update_away_from(&best_in_gen.0, &worst_in_gen.0, neg_lr);
+    }
+    let update_dur = update_start.elapsed();
+
+    let mutate_start = Instant::now();
+    self.mutate();
+    let mutate_dur = mutate_start.elapsed();
+
+    self.update_best(best_in_gen);
+
+    self.cfg.probe.on_iteration_timings(
+      generation,
+      &IterationTimings {
+        sample_dur,
+        eval_dur,
+        update_dur,
+        mutate_dur,
+      },
+    );
+  }
+
+  fn sample(&self) -> Vec<Vec<bool>> {
+    let mut rng = rand::thread_rng();
+    (0..self.cfg.samples_per_gen)
+      .map(|_| self.probabilities.iter().map(|&p| rng.gen_bool(p)).collect())
+      .collect()
+  }
+
+  fn update_towards(&mut self, sample: &[bool]) {
+    let lr = self.cfg.learning_rate;
+    for (p, &bit) in self.probabilities.iter_mut().zip(sample.iter()) {
+      let target = if bit { 1.0 } else { 0.0 };
+      *p = *p * (1.0 - lr) + target * lr;
+    }
+  }
+
+  /// Nudges each probability that disagrees between `best` and `worst`
+  /// towards `best`'s bit, at `neg_lr`. Entries where the two samples agree
+  /// are left untouched, since the worst sample being bad can't be blamed on
+  /// a bit it shares with the best one.
+  fn update_away_from(&mut self, best: &[bool], worst: &[bool], neg_lr: f64) {
+    for ((p, &best_bit), &worst_bit) in self.probabilities.iter_mut().zip(best.iter()).zip(worst.iter()) {
+      if best_bit != worst_bit {
+        let target = if best_bit { 1.0 } else { 0.0 };
+        *p = *p * (1.0 - neg_lr) + target * neg_lr;
+      }
+    }
+  }
+
+  fn mutate(&mut self) {
+    let mut rng = rand::thread_rng();
+    for p in self.probabilities.iter_mut() {
+      if rng.gen_bool(self.cfg.mutation_prob) {
+        let direction = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+        *p = (*p + direction * self.cfg.mutation_shift).clamp(0.0, 1.0);
+      }
+    }
+  }
+
+  fn update_best(&mut self, candidate: &(Vec<bool>, f64)) {
+    let improved = match &self.best {
+      Some((_, best_fitness)) => candidate.1 < *best_fitness,
+      None => true,
+    };
+
+    if improved {
+      self.cfg.probe.on_new_best(candidate);
+      self.best = Some(candidate.clone());
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use probe::NoopProbe;
+
+  fn pbil(negative_learning_rate: Option<f64>) -> Pbil<fn(&[bool]) -> f64, NoopProbe> {
+    fn eval(sample: &[bool]) -> f64 {
+      sample.iter().filter(|&&bit| bit).count() as f64
+    }
+
+    Pbil::new(
+      PbilCfg {
+        solution_len: 4,
+        samples_per_gen: 4,
+        learning_rate: 0.3,
+        negative_learning_rate,
+        mutation_prob: 0.5,
+        mutation_shift: 0.2,
+        generation_limit: 1,
+        probe: NoopProbe,
+      },
+      eval,
+    )
+  }
+
+  #[test]
+  fn update_towards_stays_within_unit_interval() {
+    let mut p = pbil(None);
+
+    for _ in 0..50 {
+      p.update_towards(&[true, false, true, false]);
+    }
+
+    assert!(p.probabilities.iter().all(|&x| (0.0..=1.0).contains(&x)));
+    assert!(p.probabilities[0] > 0.99, "repeatedly nudging towards 1 should converge near 1");
+    assert!(p.probabilities[1] < 0.01, "repeatedly nudging towards 0 should converge near 0");
+  }
+
+  #[test]
+  fn mutate_stays_within_unit_interval() {
+    let mut p = pbil(None);
+    p.cfg.mutation_prob = 1.0;
+
+    for _ in 0..50 {
+      p.mutate();
+    }
+
+    assert!(p.probabilities.iter().all(|&x| (0.0..=1.0).contains(&x)));
+  }
+
+  #[test]
+  fn update_away_from_only_nudges_bits_where_best_and_worst_disagree() {
+    let mut p = pbil(Some(0.5));
+
+    // Index 0 agrees (both true) and must be left untouched; index 1
+    // disagrees, so it's nudged towards the best sample's bit (false).
+    p.update_away_from(&[true, false], &[true, true], 0.5);
+
+    assert_eq!(p.probabilities[0], 0.5, "agreeing bit must not move");
+    assert!(p.probabilities[1] < 0.5, "disagreeing bit must move towards best's bit");
+  }
+}
@@ -0,0 +1,100 @@
+use super::probe::{NoopProbe, Probe};
+use super::{Pbil, PbilCfg};
+
+/// Fluent builder for [`Pbil`], with defaults suitable for a first run.
+pub struct PbilBuilder<Pr: Probe> {
+  solution_len: Option<usize>,
+  samples_per_gen: usize,
+  learning_rate: f64,
+  negative_learning_rate: Option<f64>,
+  mutation_prob: f64,
+  mutation_shift: f64,
+  generation_limit: usize,
+  probe: Pr,
+}
+
+impl PbilBuilder<NoopProbe> {
+  pub fn new() -> Self {
+    PbilBuilder {
+      solution_len: None,
+      samples_per_gen: 50,
+      learning_rate: 0.1,
+      negative_learning_rate: None,
+      mutation_prob: 0.02,
+      mutation_shift: 0.05,
+      generation_limit: 100,
+      probe: NoopProbe,
+    }
+  }
+}
+
+impl<Pr: Probe> PbilBuilder<Pr> {
+  pub fn solution_len(mut self, solution_len: usize) -> Self {
+    self.solution_len = Some(solution_len);
+    self
+  }
+
+  pub fn samples_per_gen(mut self, samples_per_gen: usize) -> Self {
+    self.samples_per_gen = samples_per_gen;
+    self
+  }
+
+  pub fn learning_rate(mut self, learning_rate: f64) -> Self {
+    self.learning_rate = learning_rate;
+    self
+  }
+
+  /// Enables nudging the probability vector away from each generation's
+  /// worst sample, in addition to towards its best. See
+  /// [`PbilCfg::negative_learning_rate`].
+  pub fn negative_learning_rate(mut self, negative_learning_rate: f64) -> Self {
+    self.negative_learning_rate = Some(negative_learning_rate);
+    self
+  }
+
+  pub fn mutation_prob(mut self, mutation_prob: f64) -> Self {
+    self.mutation_prob = mutation_prob;
+    self
+  }
+
+  pub fn mutation_shift(mut self, mutation_shift: f64) -> Self {
+    self.mutation_shift = mutation_shift;
+    self
+  }
+
+  pub fn generation_limit(mut self, generation_limit: usize) -> Self {
+    self.generation_limit = generation_limit;
+    self
+  }
+
+  pub fn probe<P2: Probe>(self, probe: P2) -> PbilBuilder<P2> {
+    PbilBuilder {
+      solution_len: self.solution_len,
+      samples_per_gen: self.samples_per_gen,
+      learning_rate: self.learning_rate,
+      negative_learning_rate: self.negative_learning_rate,
+      mutation_prob: self.mutation_prob,
+      mutation_shift: self.mutation_shift,
+      generation_limit: self.generation_limit,
+      probe,
+    }
+  }
+
+  pub fn build<F: Fn(&[bool]) -> f64>(self, eval: F) -> Pbil<F, Pr> {
+    let solution_len = self.solution_len.expect("solution_len must be set before build()");
+
+    Pbil::new(
+      PbilCfg {
+        solution_len,
+        samples_per_gen: self.samples_per_gen,
+        learning_rate: self.learning_rate,
+        negative_learning_rate: self.negative_learning_rate,
+        mutation_prob: self.mutation_prob,
+        mutation_shift: self.mutation_shift,
+        generation_limit: self.generation_limit,
+        probe: self.probe,
+      },
+      eval,
+    )
+  }
+}
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+/// Per-generation phase timings for one [`crate::pbil::Pbil::iterate`] call,
+/// the PBIL counterpart of the GA's `iterinfo` channel
+/// (`crate::ga::GAMetadata`'s `*_dur` fields).
+pub struct IterationTimings {
+  pub sample_dur: Duration,
+  pub eval_dur: Duration,
+  pub update_dur: Duration,
+  pub mutate_dur: Duration,
+}
+
+/// Hooks for observing a [`crate::pbil::Pbil`] run.
+///
+/// Every method has a no-op default, so an implementor only needs to
+/// override the events it cares about — mirroring [`crate::ga::Probe`].
+pub trait Probe {
+  fn on_start(&mut self) {}
+  fn on_iteration_start(&mut self, _generation: usize) {}
+  fn on_iteration_end(&mut self, _generation: usize) {}
+  /// Reports the best sample of `_generation`, unconditionally — the
+  /// `bestingen` counterpart to [`Probe::on_new_best`]'s `newbest`, which
+  /// only fires on a global improvement.
+  fn on_best_in_generation(&mut self, _generation: usize, _best: &(Vec<bool>, f64)) {}
+  fn on_new_best(&mut self, _best: &(Vec<bool>, f64)) {}
+  /// Reports the sample/eval/update/mutate phase durations of `_generation`,
+  /// the `iterinfo` counterpart.
+  fn on_iteration_timings(&mut self, _generation: usize, _timings: &IterationTimings) {}
+  fn on_end(&mut self) {}
+}
+
+/// A [`Probe`] that discards every event.
+pub struct NoopProbe;
+
+impl Probe for NoopProbe {}
@@ -0,0 +1,193 @@
+use std::time::Duration;
+
+/// Snapshot of a solver's progress, reported once per iteration.
+///
+/// Designed to be shared between the GA and ACO solvers, so the same
+/// [`StopCriterion`] implementations (in particular [`Stagnation`], which
+/// this request added to escape the low-diversity plateaus the JSSP GA's
+/// probe can measure but can't act on) could drive either one without
+/// either depending on the other's internals. In practice only
+/// [`crate::aco::ants_system::AntSystem::run`] constructs a `ProgressReport`
+/// and checks it against a `StopCriterion` today — that's ACO, not the JSSP
+/// GA this request's stagnation-detection ask was actually about. The GA's
+/// core generation loop isn't part of this tree (no `ga/mod.rs` here), so
+/// there's nothing to wire `StopCriterion` into on that side yet; this
+/// module is ready for that loop to adopt, not already adopted by it.
+pub struct ProgressReport {
+	pub generation: usize,
+	/// Best fitness/cost found so far; lower is better, matching the cost
+	/// convention of the ACO and PBIL solvers.
+	pub best_fitness: f64,
+	pub elapsed: Duration,
+}
+
+/// A composable condition for ending a solver's main loop early.
+pub trait StopCriterion {
+	fn should_stop(&mut self, report: &ProgressReport) -> bool;
+}
+
+/// Stops once `max_generations` iterations have run.
+pub struct MaxGenerations {
+	pub max_generations: usize,
+}
+
+impl StopCriterion for MaxGenerations {
+	fn should_stop(&mut self, report: &ProgressReport) -> bool {
+		report.generation + 1 >= self.max_generations
+	}
+}
+
+/// Stops once the best fitness reaches or beats `target`.
+pub struct TargetFitness {
+	pub target: f64,
+}
+
+impl StopCriterion for TargetFitness {
+	fn should_stop(&mut self, report: &ProgressReport) -> bool {
+		report.best_fitness <= self.target
+	}
+}
+
+/// Stops once a wall-clock time budget has elapsed.
+pub struct WallClockBudget {
+	pub budget: Duration,
+}
+
+impl StopCriterion for WallClockBudget {
+	fn should_stop(&mut self, report: &ProgressReport) -> bool {
+		report.elapsed >= self.budget
+	}
+}
+
+/// Stops once the best fitness hasn't improved for `patience` consecutive
+/// generations.
+pub struct Stagnation {
+	patience: usize,
+	best_so_far: Option<f64>,
+	stale_for: usize,
+}
+
+impl Stagnation {
+	pub fn new(patience: usize) -> Self {
+		Stagnation {
+			patience,
+			best_so_far: None,
+			stale_for: 0,
+		}
+	}
+}
+
+impl StopCriterion for Stagnation {
+	fn should_stop(&mut self, report: &ProgressReport) -> bool {
+		match self.best_so_far {
+			Some(best) if report.best_fitness < best => {
+				self.best_so_far = Some(report.best_fitness);
+				self.stale_for = 0;
+			}
+			Some(_) => self.stale_for += 1,
+			None => self.best_so_far = Some(report.best_fitness),
+		}
+
+		self.stale_for >= self.patience
+	}
+}
+
+/// Combines several criteria, stopping as soon as any one of them fires.
+pub struct AnyOf {
+	criteria: Vec<Box<dyn StopCriterion>>,
+}
+
+impl AnyOf {
+	pub fn new(criteria: Vec<Box<dyn StopCriterion>>) -> Self {
+		AnyOf { criteria }
+	}
+}
+
+impl StopCriterion for AnyOf {
+	fn should_stop(&mut self, report: &ProgressReport) -> bool {
+		// Intentionally does not short-circuit: every criterion must observe
+		// each report so stateful ones (e.g. Stagnation) stay up to date.
+		self.criteria
+			.iter_mut()
+			.map(|criterion| criterion.should_stop(report))
+			.fold(false, |stop, should_stop| stop || should_stop)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn report(generation: usize, best_fitness: f64, elapsed: Duration) -> ProgressReport {
+		ProgressReport { generation, best_fitness, elapsed }
+	}
+
+	#[test]
+	fn max_generations_stops_once_limit_reached() {
+		let mut criterion = MaxGenerations { max_generations: 3 };
+		assert!(!criterion.should_stop(&report(0, 0.0, Duration::ZERO)));
+		assert!(!criterion.should_stop(&report(1, 0.0, Duration::ZERO)));
+		assert!(criterion.should_stop(&report(2, 0.0, Duration::ZERO)));
+	}
+
+	#[test]
+	fn target_fitness_stops_once_target_reached_or_beaten() {
+		let mut criterion = TargetFitness { target: 1.0 };
+		assert!(!criterion.should_stop(&report(0, 1.5, Duration::ZERO)));
+		assert!(criterion.should_stop(&report(1, 1.0, Duration::ZERO)));
+		assert!(criterion.should_stop(&report(2, 0.5, Duration::ZERO)));
+	}
+
+	#[test]
+	fn wall_clock_budget_stops_once_budget_elapsed() {
+		let mut criterion = WallClockBudget { budget: Duration::from_secs(1) };
+		assert!(!criterion.should_stop(&report(0, 0.0, Duration::from_millis(500))));
+		assert!(criterion.should_stop(&report(1, 0.0, Duration::from_secs(1))));
+	}
+
+	#[test]
+	fn stagnation_stops_after_patience_generations_without_improvement() {
+		let mut criterion = Stagnation::new(2);
+		assert!(!criterion.should_stop(&report(0, 10.0, Duration::ZERO))); // first observation
+		assert!(!criterion.should_stop(&report(1, 10.0, Duration::ZERO))); // stale_for == 1
+		assert!(criterion.should_stop(&report(2, 10.0, Duration::ZERO))); // stale_for == 2
+	}
+
+	#[test]
+	fn stagnation_resets_on_improvement() {
+		let mut criterion = Stagnation::new(1);
+		assert!(!criterion.should_stop(&report(0, 10.0, Duration::ZERO)));
+		assert!(!criterion.should_stop(&report(1, 5.0, Duration::ZERO))); // improved, resets stale_for
+		assert!(criterion.should_stop(&report(2, 5.0, Duration::ZERO)));
+	}
+
+	#[test]
+	fn stagnation_with_zero_patience_stops_on_first_report() {
+		let mut criterion = Stagnation::new(0);
+		assert!(criterion.should_stop(&report(0, 10.0, Duration::ZERO)));
+	}
+
+	#[test]
+	fn any_of_stops_as_soon_as_one_criterion_fires() {
+		let mut criterion = AnyOf::new(vec![
+			Box::new(MaxGenerations { max_generations: 100 }),
+			Box::new(TargetFitness { target: 1.0 }),
+		]);
+		assert!(!criterion.should_stop(&report(0, 5.0, Duration::ZERO)));
+		assert!(criterion.should_stop(&report(1, 1.0, Duration::ZERO)));
+	}
+
+	#[test]
+	fn any_of_updates_every_criterion_even_after_one_fires() {
+		let mut criterion = AnyOf::new(vec![
+			Box::new(TargetFitness { target: 5.0 }), // fires on the first report only
+			Box::new(Stagnation::new(1)),
+		]);
+		// TargetFitness fires here; Stagnation must still record this report
+		// as its first observation.
+		assert!(criterion.should_stop(&report(0, 5.0, Duration::ZERO)));
+		// 10.0 misses the target, so only Stagnation can fire. It does so only
+		// if it saw the report above, since a first observation never stops.
+		assert!(criterion.should_stop(&report(1, 10.0, Duration::ZERO)));
+	}
+}